@@ -13,19 +13,117 @@ use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
-/// Generates an uncompressed tar archive with `control`, `md5sums`, and others
-pub fn generate_archive(options: &Config, time: u64, asset_hashes: HashMap<PathBuf, Digest>, listener: &mut dyn Listener) -> CDResult<Vec<u8>> {
+/// Codec used to compress a `control.tar`/`data.tar` member before it's
+/// stored in the outer `ar` archive, as selected by `[package.metadata.deb]
+/// compression`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionFormat {
+    Xz,
+    Zstd,
+    Gzip,
+    None,
+}
+
+/// `compression` config: algorithm, level, and (for `xz`) an explicit LZMA
+/// dictionary/window size. Larger windows shrink `.deb` size for packages
+/// shipping large binaries, at the cost of higher `dpkg` decompression
+/// memory use.
+#[derive(Debug, Clone)]
+pub struct CompressionConfig {
+    pub format: CompressionFormat,
+    pub level: u32,
+    pub xz_window_size: Option<u32>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self { format: CompressionFormat::Xz, level: 6, xz_window_size: None }
+    }
+}
+
+/// Compresses `data` with the codec configured in `options.compression`,
+/// returning the compressed bytes together with the file extension
+/// (`xz`/`zst`/`gz`, or empty for `none`) the `ar` assembly step should
+/// give this archive member (`control.tar.<ext>` / `data.tar.<ext>`).
+pub fn compress_archive(data: &[u8], options: &Config) -> CDResult<(Vec<u8>, &'static str)> {
+    let compression = &options.compression;
+    match compression.format {
+        CompressionFormat::Xz => {
+            validate_compression_level(compression.format, compression.level, 0..=9)?;
+            Ok((compress_xz(data, compression.level, compression.xz_window_size)?, "xz"))
+        },
+        CompressionFormat::Zstd => {
+            validate_compression_level(compression.format, compression.level, 0..=22)?;
+            let compressed = zstd::stream::encode_all(data, compression.level as i32).map_err(CargoDebError::Io)?;
+            Ok((compressed, "zst"))
+        },
+        CompressionFormat::Gzip => {
+            validate_compression_level(compression.format, compression.level, 0..=9)?;
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::new(compression.level));
+            encoder.write_all(data)?;
+            Ok((encoder.finish().map_err(CargoDebError::Io)?, "gz"))
+        },
+        CompressionFormat::None => Ok((data.to_vec(), "")),
+    }
+}
+
+/// Checks that `level` is within the range the chosen codec actually
+/// accepts (xz/gzip: 0-9, zstd: 0-22), instead of letting an out-of-range
+/// value reach e.g. `flate2::Compression::new`, which panics rather than
+/// returning an error.
+fn validate_compression_level(format: CompressionFormat, level: u32, range: std::ops::RangeInclusive<u32>) -> CDResult<()> {
+    if range.contains(&level) {
+        Ok(())
+    } else {
+        Err(CargoDebError::InvalidCompressionLevel(format, level))
+    }
+}
+
+/// The xz encoding shared by `compress_archive`'s `Xz` arm and
+/// `generate_source_tarball`, which always uses xz regardless of
+/// `options.compression` so the `.dsc`'s declared tarball name and
+/// checksums never disagree with its actual codec.
+fn compress_xz(data: &[u8], level: u32, xz_window_size: Option<u32>) -> CDResult<Vec<u8>> {
+    let mut filters = xz2::stream::Filters::new();
+    let mut lzma_opts = xz2::stream::LzmaOptions::new_preset(level).map_err(CargoDebError::LzmaCompressionError)?;
+    if let Some(window_size) = xz_window_size {
+        lzma_opts.dict_size(window_size);
+    }
+    filters.lzma2(&lzma_opts);
+    let stream = xz2::stream::Stream::new_stream_encoder(&filters, xz2::stream::Check::Crc32)
+        .map_err(CargoDebError::LzmaCompressionError)?;
+    let mut encoder = xz2::write::XzEncoder::new_stream(Vec::new(), stream);
+    encoder.write_all(data)?;
+    encoder.finish().map_err(CargoDebError::Io)
+}
+
+/// Generates an uncompressed tar archive with `control`, `md5sums`, and others,
+/// then compresses it with the codec chosen by `options.compression`.
+///
+/// Also prepares the DEP-5 copyright document as a data-archive asset and
+/// records its hash into `./md5sums` alongside every other resolved asset,
+/// so it doesn't silently go uninstalled or unhashed.
+///
+/// Returns the compressed control archive bytes, the member extension
+/// (`xz`/`zst`/`gz`, or empty for `none`) so the caller can name the
+/// `control.tar.<ext>` member correctly when assembling the outer `ar`
+/// archive, and the copyright asset's (target path, bytes) for the caller
+/// to install into `data.tar` (which is compressed the same way, with the
+/// same `options.compression`, by the data-archive writer).
+pub fn generate_archive(options: &Config, time: u64, asset_hashes: HashMap<PathBuf, Digest>, listener: &mut dyn Listener) -> CDResult<(Vec<u8>, &'static str, (PathBuf, Vec<u8>))> {
     let mut archive = Archive::new(time);
-    generate_md5sums(&mut archive, options, asset_hashes)?;
+    let copyright_asset = generate_copyright_asset(options)?;
+    generate_md5sums(&mut archive, options, asset_hashes, &copyright_asset)?;
     generate_control(&mut archive, options, listener)?;
-    if let Some(ref files) = options.conf_files {
-        generate_conf_files(&mut archive, files)?;
+    if options.conf_files.is_some() || options.auto_conf_files {
+        generate_conf_files(&mut archive, options)?;
     }
     generate_scripts(&mut archive, options, listener)?;
     if let Some(ref file) = options.triggers_file {
         generate_triggers_file(&mut archive, file)?;
     }
-    Ok(archive.into_inner()?)
+    let (compressed, ext) = compress_archive(&archive.into_inner()?, options)?;
+    Ok((compressed, ext, copyright_asset))
 }
 
 /// Append Debian maintainer script files (control, preinst, postinst, prerm,
@@ -115,7 +213,7 @@ fn generate_scripts(archive: &mut Archive, option: &Config, listener: &mut dyn L
 }
 
 /// Creates the md5sums file which contains a list of all contained files and the md5sums of each.
-fn generate_md5sums(archive: &mut Archive, options: &Config, asset_hashes: HashMap<PathBuf, Digest>) -> CDResult<()> {
+fn generate_md5sums(archive: &mut Archive, options: &Config, asset_hashes: HashMap<PathBuf, Digest>, extra_assets: &(PathBuf, Vec<u8>)) -> CDResult<()> {
     let mut md5sums: Vec<u8> = Vec::new();
 
     // Collect md5sums from each asset in the archive (excludes symlinks).
@@ -129,6 +227,14 @@ fn generate_md5sums(archive: &mut Archive, options: &Config, asset_hashes: HashM
         }
     }
 
+    // Assets generated by cargo-deb itself (currently just the copyright
+    // document) aren't part of `options.assets.resolved`, so hash them here.
+    let (extra_path, extra_content) = extra_assets;
+    write!(md5sums, "{:x}", md5::compute(extra_content))?;
+    md5sums.write_all(b"  ")?;
+    md5sums.write_all(&extra_path.as_path().as_unix_path())?;
+    md5sums.write_all(&[b'\n'])?;
+
     // Write the data to the archive
     archive.file("./md5sums", &md5sums, 0o644)?;
     Ok(())
@@ -136,79 +242,143 @@ fn generate_md5sums(archive: &mut Archive, options: &Config, asset_hashes: HashM
 
 /// Generates the control file that obtains all the important information about the package.
 fn generate_control(archive: &mut Archive, options: &Config, listener: &mut dyn Listener) -> CDResult<()> {
+    validate_standards_version(&options.standards_version)?;
+    validate_priority(&options.priority.to_string())?;
+    if let Some(ref section) = options.section {
+        validate_section(section)?;
+    }
+
     // Create and return the handle to the control file with write access.
     let mut control: Vec<u8> = Vec::with_capacity(1024);
+    write_binary_stanza(&mut control, options, listener)?;
 
+    // Add the control file to the tar archive.
+    archive.file("./control", &control, 0o644)?;
+    Ok(())
+}
+
+/// Writes the binary-package stanza (the part of `debian/control` describing
+/// the installable `.deb`) to `control`. Shared by `generate_control`, which
+/// ships it inside the binary `.deb`'s control archive, and
+/// `generate_source_control`, which appends it after the source-package
+/// stanza in a buildable `debian/control` file.
+fn write_binary_stanza(control: &mut Vec<u8>, options: &Config, listener: &mut dyn Listener) -> CDResult<()> {
     // Write all of the lines required by the control file.
-    writeln!(&mut control, "Package: {}", options.deb_name)?;
-    writeln!(&mut control, "Version: {}", options.deb_version)?;
-    writeln!(&mut control, "Architecture: {}", options.architecture)?;
+    writeln!(control, "Package: {}", options.deb_name)?;
+    writeln!(control, "Version: {}", options.deb_version)?;
+    writeln!(control, "Architecture: {}", options.architecture)?;
+    if let Some(ref multi_arch) = options.multi_arch {
+        writeln!(control, "Multi-Arch: {}", multi_arch)?;
+    }
     if let Some(ref repo) = options.repository {
         if repo.starts_with("http") {
-            writeln!(&mut control, "Vcs-Browser: {}", repo)?;
+            writeln!(control, "Vcs-Browser: {}", repo)?;
         }
         if let Some(kind) = options.repository_type() {
-            writeln!(&mut control, "Vcs-{}: {}", kind, repo)?;
+            writeln!(control, "Vcs-{}: {}", kind, repo)?;
         }
     }
     if let Some(homepage) = options.homepage.as_ref().or(options.documentation.as_ref()) {
-        writeln!(&mut control, "Homepage: {}", homepage)?;
+        writeln!(control, "Homepage: {}", homepage)?;
     }
     if let Some(ref section) = options.section {
-        writeln!(&mut control, "Section: {}", section)?;
+        writeln!(control, "Section: {}", section)?;
     }
-    writeln!(&mut control, "Priority: {}", options.priority)?;
-    control.write_all(b"Standards-Version: 3.9.4\n")?;
-    writeln!(&mut control, "Maintainer: {}", options.maintainer)?;
+    writeln!(control, "Priority: {}", options.priority)?;
+    writeln!(control, "Standards-Version: {}", options.standards_version)?;
+    writeln!(control, "Maintainer: {}", options.maintainer)?;
 
     let installed_size = options.assets.resolved
         .iter()
         .filter_map(|m| m.source.len())
         .sum::<u64>() / 1024;
 
-    writeln!(&mut control, "Installed-Size: {}", installed_size)?;
+    writeln!(control, "Installed-Size: {}", installed_size)?;
 
-    writeln!(&mut control, "Depends: {}", options.get_dependencies(listener)?)?;
+    if options.pre_depends.is_some() {
+        writeln!(control, "Pre-Depends: {}", options.get_pre_dependencies(listener)?)?;
+    }
+
+    writeln!(control, "Depends: {}", options.get_dependencies(listener)?)?;
 
     if let Some(ref build_depends) = options.build_depends {
-        writeln!(&mut control, "Build-Depends: {}", build_depends)?;
+        writeln!(control, "Build-Depends: {}", build_depends)?;
     }
 
     if let Some(ref conflicts) = options.conflicts {
-        writeln!(&mut control, "Conflicts: {}", conflicts)?;
+        writeln!(control, "Conflicts: {}", conflicts)?;
     }
     if let Some(ref breaks) = options.breaks {
-        writeln!(&mut control, "Breaks: {}", breaks)?;
+        writeln!(control, "Breaks: {}", breaks)?;
     }
     if let Some(ref replaces) = options.replaces {
-        writeln!(&mut control, "Replaces: {}", replaces)?;
+        writeln!(control, "Replaces: {}", replaces)?;
     }
     if let Some(ref provides) = options.provides {
-        writeln!(&mut control, "Provides: {}", provides)?;
+        writeln!(control, "Provides: {}", provides)?;
+    }
+    if options.recommends.is_some() {
+        writeln!(control, "Recommends: {}", options.get_recommends(listener)?)?;
+    }
+    if options.suggests.is_some() {
+        writeln!(control, "Suggests: {}", options.get_suggests(listener)?)?;
+    }
+    if options.enhances.is_some() {
+        writeln!(control, "Enhances: {}", options.get_enhances(listener)?)?;
     }
 
-    write!(&mut control, "Description:")?;
+    write!(control, "Description:")?;
     for line in options.description.split_by_chars(79) {
-        writeln!(&mut control, " {}", line)?;
+        writeln!(control, " {}", line)?;
     }
 
     if let Some(ref desc) = options.extended_description {
         for line in desc.split_by_chars(79) {
-            writeln!(&mut control, " {}", line)?;
+            writeln!(control, " {}", line)?;
         }
     }
     control.push(10);
 
-    // Add the control file to the tar archive.
-    archive.file("./control", &control, 0o644)?;
     Ok(())
 }
 
 /// If configuration files are required, the conffiles file will be created.
-fn generate_conf_files(archive: &mut Archive, files: &str) -> CDResult<()> {
+///
+/// Its contents are the explicit `conf_files` entries (if any), merged with
+/// every resolved asset installed under `/etc` when `auto_conf_files` is
+/// enabled, de-duplicated and listed one absolute path per line as dpkg
+/// expects.
+fn generate_conf_files(archive: &mut Archive, options: &Config) -> CDResult<()> {
+    let mut paths: Vec<Vec<u8>> = Vec::new();
+
+    if let Some(ref files) = options.conf_files {
+        paths.extend(files.lines().map(|line| line.as_bytes().to_vec()));
+    }
+
+    if options.auto_conf_files {
+        for asset in &options.assets.resolved {
+            if asset.target_path.starts_with("etc") {
+                let mut path = vec![b'/'];
+                path.extend_from_slice(&asset.target_path.as_path().as_unix_path());
+                paths.push(path);
+            }
+        }
+    }
+
+    paths.sort();
+    paths.dedup();
+
+    // Auto-detection with no asset under /etc and no explicit conf_files
+    // leaves nothing to list; don't ship an empty conffiles member.
+    if paths.is_empty() {
+        return Ok(());
+    }
+
     let mut data = Vec::new();
-    data.write_all(files.as_bytes())?;
-    data.push(b'\n');
+    for path in &paths {
+        data.write_all(path)?;
+        data.push(b'\n');
+    }
     archive.file("./conffiles", &data, 0o644)?;
     Ok(())
 }
@@ -219,3 +389,419 @@ fn generate_triggers_file<P: AsRef<Path>>(archive: &mut Archive, path: P) -> CDR
     }
     Ok(())
 }
+
+/// Generates the contents of a DEP-5 "machine-readable debian/copyright"
+/// document (`./usr/share/doc/<pkg>/copyright`) describing the license(s)
+/// that apply to this package.
+///
+/// Unlike `generate_control` and `generate_md5sums`, this does not write
+/// into the control archive: the copyright file lives in the data archive
+/// alongside the rest of the installed files. Use `generate_copyright_asset`
+/// to get installable (path, bytes) ready for the data archive, with its
+/// hash recorded into `./md5sums` by `generate_archive`.
+pub fn generate_copyright(options: &Config) -> CDResult<Vec<u8>> {
+    let mut copyright: Vec<u8> = Vec::with_capacity(512);
+
+    writeln!(&mut copyright, "Format: https://www.debian.org/doc/packaging-manuals/copyright-format/1.0/")?;
+    writeln!(&mut copyright, "Upstream-Name: {}", options.name)?;
+    if let Some(ref repo) = options.repository {
+        writeln!(&mut copyright, "Source: {}", repo)?;
+    }
+    copyright.push(10);
+
+    let licenses = options.license_names()?;
+    let holders = options.authors.join(", ");
+
+    // A single `Files: *` paragraph covering the whole tree, with one
+    // combined `License:` short name (e.g. `MIT or Apache-2.0`) rather than
+    // one paragraph per license — lintian flags repeated `Files: *`
+    // paragraphs as duplicates.
+    writeln!(&mut copyright, "Files: *")?;
+    if !holders.is_empty() {
+        writeln!(&mut copyright, "Copyright: {}", holders)?;
+    }
+    // DEP-5 requires a License: value; a crate with no license info still
+    // needs a recognized short name rather than a bare, invalid "License: ".
+    let license_field = if licenses.is_empty() { "UNKNOWN".to_owned() } else { licenses.join(" or ") };
+    writeln!(&mut copyright, "License: {}", license_field)?;
+    copyright.push(10);
+
+    for license in &licenses {
+        if let Some(text) = standard_license_text(license) {
+            writeln!(&mut copyright, "License: {}", license)?;
+            for line in text.lines() {
+                if line.is_empty() {
+                    writeln!(&mut copyright, " .")?;
+                } else {
+                    writeln!(&mut copyright, " {}", line)?;
+                }
+            }
+            copyright.push(10);
+        }
+    }
+
+    Ok(copyright)
+}
+
+/// Size above which the copyright document is gzip-compressed before
+/// installation, matching dpkg's own convention for installed docs; smaller
+/// files are stored plain since per-file gzip overhead would outweigh the
+/// saving.
+const COPYRIGHT_GZIP_THRESHOLD: usize = 4096;
+
+/// Prepares the DEP-5 copyright document as an installable data-archive
+/// asset: the target path under `/usr/share/doc/<pkg>/` (gzip-compressed,
+/// with a `.gz` suffix, when larger than `COPYRIGHT_GZIP_THRESHOLD`) and its
+/// bytes.
+pub fn generate_copyright_asset(options: &Config) -> CDResult<(PathBuf, Vec<u8>)> {
+    let content = generate_copyright(options)?;
+    let doc_dir = format!("usr/share/doc/{}", options.deb_name);
+
+    if content.len() > COPYRIGHT_GZIP_THRESHOLD {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(&content)?;
+        let compressed = encoder.finish().map_err(CargoDebError::Io)?;
+        Ok((PathBuf::from(format!("{}/copyright.gz", doc_dir)), compressed))
+    } else {
+        Ok((PathBuf::from(format!("{}/copyright", doc_dir)), content))
+    }
+}
+
+/// Returns the bundled text of a well-known SPDX short license identifier,
+/// for inclusion as a trailing stand-alone `License:` paragraph. The MIT
+/// text is complete; the Apache-2.0 text is abridged (sections 3 to 9 are
+/// shortened and the appendix is omitted), not the full license.
+/// Unrecognized identifiers are left without a trailing paragraph, since
+/// their text isn't known to us.
+fn standard_license_text(spdx_id: &str) -> Option<&'static str> {
+    match spdx_id {
+        "MIT" => Some(include_str!("licenses/mit.txt")),
+        "Apache-2.0" => Some(include_str!("licenses/apache-2.0.txt")),
+        _ => None,
+    }
+}
+
+/// Generates the contents of a buildable `debian/control` file: a
+/// source-package stanza followed by the binary-package stanza, separated
+/// by a blank line as `dpkg-source` expects.
+///
+/// The binary stanza is the same one that ends up inside the `.deb`'s
+/// control archive, written by `write_binary_stanza`; only the leading
+/// source stanza is specific to the source package.
+pub fn generate_source_control(options: &Config, listener: &mut dyn Listener) -> CDResult<Vec<u8>> {
+    validate_standards_version(&options.standards_version)?;
+    validate_priority(&options.priority.to_string())?;
+    if let Some(ref section) = options.section {
+        validate_section(section)?;
+    }
+
+    let mut control: Vec<u8> = Vec::with_capacity(1024);
+
+    writeln!(&mut control, "Source: {}", options.name)?;
+    writeln!(&mut control, "Maintainer: {}", options.maintainer)?;
+    if let Some(ref section) = options.section {
+        writeln!(&mut control, "Section: {}", section)?;
+    }
+    writeln!(&mut control, "Priority: {}", options.priority)?;
+    writeln!(&mut control, "Standards-Version: {}", options.standards_version)?;
+    if let Some(ref build_depends) = options.build_depends {
+        writeln!(&mut control, "Build-Depends: {}", build_depends)?;
+    }
+    if let Some(ref homepage) = options.homepage.as_ref().or(options.documentation.as_ref()) {
+        writeln!(&mut control, "Homepage: {}", homepage)?;
+    }
+    control.push(10);
+
+    write_binary_stanza(&mut control, options, listener)?;
+
+    Ok(control)
+}
+
+/// Synthesizes the `debian/rules` makefile for the source package, whose
+/// `override_dh_auto_build` target invokes `cargo build --release` instead
+/// of letting `dh_auto_build` guess at the build system.
+pub fn generate_source_rules() -> Vec<u8> {
+    let mut rules = Vec::new();
+    rules.extend_from_slice(b"#!/usr/bin/make -f\n\n");
+    rules.extend_from_slice(b"%:\n\tdh $@\n\n");
+    rules.extend_from_slice(b"override_dh_auto_build:\n\tcargo build --release\n");
+    rules
+}
+
+/// The debhelper compatibility level declared by the generated
+/// `debian/compat`. `dh` has required at least this level since long before
+/// any cargo-deb-supported distribution release, so it's not user-configurable.
+const DEBHELPER_COMPAT_LEVEL: u8 = 10;
+
+/// Synthesizes `debian/compat`.
+pub fn generate_source_compat() -> Vec<u8> {
+    format!("{}\n", DEBHELPER_COMPAT_LEVEL).into_bytes()
+}
+
+/// Synthesizes a minimal `debian/changelog` with a single entry for the
+/// package's current version, which is all `dpkg-buildpackage` and lintian
+/// require to be present and well-formed.
+pub fn generate_source_changelog(options: &Config, time: u64) -> CDResult<Vec<u8>> {
+    let mut changelog: Vec<u8> = Vec::with_capacity(256);
+
+    writeln!(&mut changelog, "{} ({}) UNRELEASED; urgency=medium", options.deb_name, options.deb_version)?;
+    changelog.push(10);
+    writeln!(&mut changelog, "  * Packaged by cargo-deb.")?;
+    changelog.push(10);
+    writeln!(&mut changelog, " -- {}  {}", options.maintainer, rfc2822_timestamp(time))?;
+
+    Ok(changelog)
+}
+
+/// Formats a Unix timestamp as the RFC 2822 date `debian/changelog` entries
+/// are terminated with (e.g. `Sat, 01 Jan 2022 00:00:00 +0000`), always in
+/// UTC since the source package carries no timezone of its own.
+fn rfc2822_timestamp(time: u64) -> String {
+    const DAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"]; // 1970-01-01 was a Thursday
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+    let days_since_epoch = time / 86400;
+    let seconds_of_day = time % 86400;
+    let (hour, minute, second) = (seconds_of_day / 3600, (seconds_of_day % 3600) / 60, seconds_of_day % 60);
+
+    let mut days_left = days_since_epoch as i64;
+    let mut year = 1970i64;
+    loop {
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if days_left < days_in_year {
+            break;
+        }
+        days_left -= days_in_year;
+        year += 1;
+    }
+
+    let month_lengths = [31, if is_leap_year(year) { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let mut month = 0;
+    for &len in &month_lengths {
+        if days_left < len {
+            break;
+        }
+        days_left -= len;
+        month += 1;
+    }
+
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} +0000",
+        DAYS[(days_since_epoch % 7) as usize],
+        days_left + 1,
+        MONTHS[month],
+        year,
+        hour,
+        minute,
+        second,
+    )
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Collects the maintainer scripts (`preinst`, `postinst`, `prerm`,
+/// `postrm`, `config`, `templates`) that `options.maintainer_scripts` makes
+/// available, for inclusion directly under `debian/` as dh expects — unlike
+/// `generate_scripts`, these aren't wrapped in a tar archive.
+fn collect_source_maintainer_scripts(options: &Config) -> Vec<(PathBuf, Vec<u8>)> {
+    let mut scripts = Vec::new();
+
+    if let Some(ref maintainer_scripts) = options.maintainer_scripts {
+        for name in &["config", "preinst", "postinst", "prerm", "postrm", "templates"] {
+            if let Some(script_path) = find_first(&[maintainer_scripts.clone()], name) {
+                if let Ok(content) = fs::read(&script_path) {
+                    scripts.push((PathBuf::from("debian").join(name), content));
+                }
+            }
+        }
+    }
+
+    scripts
+}
+
+/// A Debian source package: the `debian/` directory contents, the upstream
+/// source tarball, and the `.dsc` that ties them together for
+/// `dpkg-buildpackage`/`dpkg-source` to consume.
+pub struct SourcePackage {
+    /// `(path relative to the unpacked source root, contents)`, e.g.
+    /// `("debian/control", ...)`.
+    pub files: Vec<(PathBuf, Vec<u8>)>,
+    pub tarball: Vec<u8>,
+    pub dsc: Vec<u8>,
+}
+
+/// Assembles a buildable Debian source package: the `debian/` directory
+/// (`control`, `rules`, `changelog`, `compat`, the DEP-5 `copyright`, and any
+/// maintainer scripts), a single native-format source tarball holding the
+/// crate's source tree plus that `debian/` directory, and a `.dsc`
+/// describing the whole thing — so the result can be handed to
+/// `dpkg-buildpackage` or uploaded to a distribution's build service instead
+/// of only ever producing an ad-hoc binary `.deb`.
+pub fn generate_source_package(options: &Config, time: u64, listener: &mut dyn Listener) -> CDResult<SourcePackage> {
+    let mut files = vec![
+        (PathBuf::from("debian/control"), generate_source_control(options, listener)?),
+        (PathBuf::from("debian/rules"), generate_source_rules()),
+        (PathBuf::from("debian/changelog"), generate_source_changelog(options, time)?),
+        (PathBuf::from("debian/compat"), generate_source_compat()),
+        (PathBuf::from("debian/copyright"), generate_copyright(options)?),
+    ];
+    files.extend(collect_source_maintainer_scripts(options));
+
+    let tarball = generate_source_tarball(options, time, &files)?;
+    let dsc = generate_dsc(options, &tarball)?;
+
+    Ok(SourcePackage { files, tarball, dsc })
+}
+
+/// Recursively collects every file under `dir` (relative to `dir`),
+/// skipping VCS and build-output directories that have no business in an
+/// upstream source tarball, and any existing `debian/` directory, whose
+/// place the generated one takes.
+fn collect_source_tree_files(dir: &Path) -> CDResult<Vec<(PathBuf, Vec<u8>)>> {
+    let mut files = Vec::new();
+    collect_source_tree_files_into(dir, Path::new(""), &mut files)?;
+    Ok(files)
+}
+
+fn collect_source_tree_files_into(root: &Path, relative: &Path, files: &mut Vec<(PathBuf, Vec<u8>)>) -> CDResult<()> {
+    for entry in fs::read_dir(root.join(relative))? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name == "target" || name == ".git" || name == "debian" {
+            continue;
+        }
+        let entry_relative = relative.join(&name);
+        if entry.file_type()?.is_dir() {
+            collect_source_tree_files_into(root, &entry_relative, files)?;
+        } else {
+            let content = fs::read(root.join(&entry_relative))?;
+            files.push((entry_relative, content));
+        }
+    }
+    Ok(())
+}
+
+/// Packs the crate's source tree (everything under `options.package_dir`
+/// but `target/`/`.git/`/`debian/`) together with the `debian/` directory files into a
+/// single `<name>-<version>/`-prefixed tarball and compresses it with xz —
+/// unconditionally, regardless of the codec in `options.compression`, since the `.dsc`
+/// this produces (`3.0 (native)`) always names and checksums a `.tar.xz`.
+/// The configured level and window size are used only when that codec is xz.
+fn generate_source_tarball(options: &Config, time: u64, debian_files: &[(PathBuf, Vec<u8>)]) -> CDResult<Vec<u8>> {
+    let prefix = format!("{}-{}", options.deb_name, options.deb_version);
+    let mut archive = Archive::new(time);
+
+    for (path, content) in collect_source_tree_files(&options.package_dir)? {
+        let name = format!("{}/{}", prefix, String::from_utf8_lossy(&path.as_path().as_unix_path()));
+        archive.file(&name, &content, 0o644)?;
+    }
+    for (path, content) in debian_files {
+        let name = format!("{}/{}", prefix, String::from_utf8_lossy(&path.as_path().as_unix_path()));
+        archive.file(&name, content, 0o644)?;
+    }
+
+    // Another codec's level (zstd goes up to 22) isn't a valid xz preset.
+    let (level, xz_window_size) = match options.compression.format {
+        CompressionFormat::Xz => (options.compression.level, options.compression.xz_window_size),
+        _ => (CompressionConfig::default().level, None),
+    };
+    compress_xz(&archive.into_inner()?, level, xz_window_size)
+}
+
+/// Generates the unsigned `.dsc` control file: the source-package stanza
+/// (reusing `generate_source_control`'s logic isn't possible here since a
+/// `.dsc` additionally carries the tarball's checksums) plus the
+/// `Architecture`/`Package-List` fields `3.0 (native)` requires and
+/// `Checksums-Sha256`/`Files` entries for the single native tarball.
+fn generate_dsc(options: &Config, tarball: &[u8]) -> CDResult<Vec<u8>> {
+    let tarball_name = format!("{}_{}.tar.xz", options.deb_name, options.deb_version);
+    let sha256 = sha2_256_hex(tarball);
+    let md5 = format!("{:x}", md5::compute(tarball));
+
+    let mut dsc: Vec<u8> = Vec::with_capacity(512);
+    writeln!(&mut dsc, "Format: 3.0 (native)")?;
+    writeln!(&mut dsc, "Source: {}", options.name)?;
+    writeln!(&mut dsc, "Version: {}", options.deb_version)?;
+    writeln!(&mut dsc, "Maintainer: {}", options.maintainer)?;
+    writeln!(&mut dsc, "Architecture: {}", options.architecture)?;
+    writeln!(&mut dsc, "Standards-Version: {}", options.standards_version)?;
+    if let Some(ref build_depends) = options.build_depends {
+        writeln!(&mut dsc, "Build-Depends: {}", build_depends)?;
+    }
+    writeln!(&mut dsc, "Package-List:")?;
+    writeln!(&mut dsc, " {} deb {} {}", options.deb_name, options.section.as_deref().unwrap_or("unknown"), options.priority)?;
+    dsc.push(10);
+    writeln!(&mut dsc, "Checksums-Sha256:")?;
+    writeln!(&mut dsc, " {} {} {}", sha256, tarball.len(), tarball_name)?;
+    writeln!(&mut dsc, "Files:")?;
+    writeln!(&mut dsc, " {} {} {}", md5, tarball.len(), tarball_name)?;
+
+    Ok(dsc)
+}
+
+/// Hex-encoded SHA-256 via the `sha2` crate, needed only for the `.dsc`'s
+/// `Checksums-Sha256` field (the rest of this file gets by with the `md5`
+/// crate already used for `./md5sums`).
+fn sha2_256_hex(data: &[u8]) -> String {
+    use sha2::Digest as _;
+    let digest = sha2::Sha256::digest(data);
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Checks that `version` matches the Debian policy version grammar
+/// (`N.N.N` or `N.N.N.N`, e.g. `4.6.2`), as required for the
+/// `Standards-Version` control field.
+fn validate_standards_version(version: &str) -> CDResult<()> {
+    let parts: Vec<_> = version.split('.').collect();
+    let valid = (3..=4).contains(&parts.len()) && parts.iter().all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+
+    if !valid {
+        return Err(CargoDebError::InvalidStandardsVersion(version.to_owned()));
+    }
+    Ok(())
+}
+
+/// The `Priority` values defined by Debian policy §2.5.
+const VALID_PRIORITIES: &[&str] = &["required", "important", "standard", "optional", "extra"];
+
+/// Checks that `priority` is one of the policy-allowed `Priority` values.
+fn validate_priority(priority: &str) -> CDResult<()> {
+    if VALID_PRIORITIES.contains(&priority) {
+        Ok(())
+    } else {
+        Err(CargoDebError::InvalidPriority(priority.to_owned()))
+    }
+}
+
+/// The canonical Debian archive sections (policy §2.4), excluding the
+/// `contrib/`, `non-free/` and `non-free-firmware/` component prefixes,
+/// which are checked separately.
+const VALID_SECTIONS: &[&str] = &[
+    "admin", "cli-mono", "comm", "database", "debug", "devel", "doc", "editors",
+    "electronics", "embedded", "fonts", "games", "gnome", "gnu-r", "gnustep",
+    "graphics", "hamradio", "haskell", "httpd", "interpreters", "introspection",
+    "java", "javascript", "kde", "kernel", "libdevel", "libs", "lisp",
+    "localization", "mail", "math", "metapackages", "misc", "net", "news",
+    "ocaml", "oldlibs", "otherosfs", "perl", "php", "python", "ruby", "rust",
+    "science", "shells", "sound", "text", "translations", "utils", "vcs",
+    "video", "web", "x11", "xfce", "zope",
+];
+
+/// Checks that `section` is on the canonical Debian section list, allowing
+/// for the `contrib/`, `non-free/` and `non-free-firmware/` component
+/// prefixes (e.g. `non-free/games`).
+fn validate_section(section: &str) -> CDResult<()> {
+    let bare = section
+        .strip_prefix("non-free-firmware/")
+        .or_else(|| section.strip_prefix("non-free/"))
+        .or_else(|| section.strip_prefix("contrib/"))
+        .unwrap_or(section);
+
+    if VALID_SECTIONS.contains(&bare) {
+        Ok(())
+    } else {
+        Err(CargoDebError::InvalidSection(section.to_owned()))
+    }
+}